@@ -0,0 +1,155 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+/// A configurable formatter for [KeyEvent], the symmetric counterpart of `parse`.
+///
+/// You can either use the default one, with `KeyEventFormat::default()`, or
+/// build a custom one with the `with_*` builder methods, for example to
+/// get a more compact rendering:
+/// ```
+/// use crokey::{key, KeyEventFormat};
+/// let format = KeyEventFormat::default()
+///     .with_implicit_shift()
+///     .with_control("^");
+/// assert_eq!(format.to_string(key!(shift-a)), "A");
+/// assert_eq!(format.to_string(key!(ctrl-c)), "^c");
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyEventFormat {
+    /// token prepended when the control modifier is set, e.g. "Ctrl-"
+    control: String,
+    /// token prepended when the alt modifier is set, e.g. "Alt-"
+    alt: String,
+    /// token prepended when the shift modifier is set, e.g. "Shift-"
+    shift: String,
+    /// token prepended when the super modifier is set, e.g. "Super-"
+    super_: String,
+    /// token prepended when the hyper modifier is set, e.g. "Hyper-"
+    hyper: String,
+    /// token prepended when the meta modifier is set, e.g. "Meta-"
+    meta: String,
+    /// when true, a shifted character is rendered directly in its
+    /// uppercase form instead of being prefixed with the shift token
+    implicit_shift: bool,
+}
+
+impl Default for KeyEventFormat {
+    fn default() -> Self {
+        Self {
+            control: "Ctrl-".to_string(),
+            alt: "Alt-".to_string(),
+            shift: "Shift-".to_string(),
+            super_: "Super-".to_string(),
+            hyper: "Hyper-".to_string(),
+            meta: "Meta-".to_string(),
+            implicit_shift: false,
+        }
+    }
+}
+
+impl KeyEventFormat {
+    /// set the token prepended when the control modifier is set (default: "Ctrl-")
+    pub fn with_control<S: Into<String>>(mut self, control: S) -> Self {
+        self.control = control.into();
+        self
+    }
+    /// set the token prepended when the alt modifier is set (default: "Alt-")
+    pub fn with_alt<S: Into<String>>(mut self, alt: S) -> Self {
+        self.alt = alt.into();
+        self
+    }
+    /// set the token prepended when the shift modifier is set (default: "Shift-")
+    pub fn with_shift<S: Into<String>>(mut self, shift: S) -> Self {
+        self.shift = shift.into();
+        self
+    }
+    /// set the token prepended when the super modifier is set (default: "Super-")
+    pub fn with_super<S: Into<String>>(mut self, super_: S) -> Self {
+        self.super_ = super_.into();
+        self
+    }
+    /// set the token prepended when the hyper modifier is set (default: "Hyper-")
+    pub fn with_hyper<S: Into<String>>(mut self, hyper: S) -> Self {
+        self.hyper = hyper.into();
+        self
+    }
+    /// set the token prepended when the meta modifier is set (default: "Meta-")
+    pub fn with_meta<S: Into<String>>(mut self, meta: S) -> Self {
+        self.meta = meta.into();
+        self
+    }
+    /// render a shifted char directly as its uppercase form instead of
+    /// prefixing it with the shift token, e.g. "A" instead of "Shift-a"
+    pub fn with_implicit_shift(mut self) -> Self {
+        self.implicit_shift = true;
+        self
+    }
+    fn code_name(code: KeyCode) -> String {
+        match code {
+            KeyCode::Backspace => "Backspace".to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Left => "Left".to_string(),
+            KeyCode::Right => "Right".to_string(),
+            KeyCode::Up => "Up".to_string(),
+            KeyCode::Down => "Down".to_string(),
+            KeyCode::Home => "Home".to_string(),
+            KeyCode::End => "End".to_string(),
+            KeyCode::PageUp => "PageUp".to_string(),
+            KeyCode::PageDown => "PageDown".to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::BackTab => "BackTab".to_string(),
+            KeyCode::Delete => "Delete".to_string(),
+            KeyCode::Insert => "Insert".to_string(),
+            KeyCode::F(n) => format!("F{n}"),
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char('-') => "Hyphen".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Null => "Null".to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            _ => "?".to_string(),
+        }
+    }
+    /// render a key event as a string, using this format's configuration
+    pub fn to_string(&self, key_event: KeyEvent) -> String {
+        let mut s = String::new();
+        if key_event.modifiers.contains(KeyModifiers::CONTROL) {
+            s.push_str(&self.control);
+        }
+        if key_event.modifiers.contains(KeyModifiers::ALT) {
+            s.push_str(&self.alt);
+        }
+        if key_event.modifiers.contains(KeyModifiers::SUPER) {
+            s.push_str(&self.super_);
+        }
+        if key_event.modifiers.contains(KeyModifiers::HYPER) {
+            s.push_str(&self.hyper);
+        }
+        if key_event.modifiers.contains(KeyModifiers::META) {
+            s.push_str(&self.meta);
+        }
+        let mut shift = key_event.modifiers.contains(KeyModifiers::SHIFT);
+        let code_name = if self.implicit_shift {
+            if let KeyCode::Char(c) = key_event.code {
+                if shift || c.is_ascii_uppercase() {
+                    shift = false;
+                    Self::code_name(KeyCode::Char(c.to_ascii_uppercase()))
+                } else {
+                    Self::code_name(key_event.code)
+                }
+            } else {
+                Self::code_name(key_event.code)
+            }
+        } else {
+            Self::code_name(key_event.code)
+        };
+        if shift {
+            s.push_str(&self.shift);
+        }
+        s.push_str(&code_name);
+        match key_event.kind {
+            KeyEventKind::Press => {}
+            KeyEventKind::Repeat => s.push_str(":repeat"),
+            KeyEventKind::Release => s.push_str(":release"),
+        }
+        s
+    }
+}
@@ -0,0 +1,174 @@
+//! A mode-aware keybinding layer built on top of [KeySequence], mirroring
+//! the `HashMap<Mode, HashMap<KeyEvent, Command>>` shape used by modal
+//! editors such as Helix.
+
+use {
+    crate::{KeySequence, SequenceStatus},
+    crossterm::event::KeyEvent,
+    std::{
+        collections::HashMap,
+        hash::Hash,
+    },
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A map from key sequences to actions, for a single mode.
+///
+/// ```
+/// use crokey::{key_seq, Keymap};
+/// let mut keymap: Keymap<String> = Keymap::default();
+/// keymap.insert(key_seq!(ctrl-s), "save".to_string());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Keymap<A>(HashMap<KeySequence, A>);
+
+impl<A> Default for Keymap<A> {
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<A> Keymap<A> {
+    /// bind a key sequence to an action, replacing any previous binding
+    pub fn insert(&mut self, sequence: KeySequence, action: A) -> Option<A> {
+        self.0.insert(sequence, action)
+    }
+
+    /// feed an input buffer of already received key events to this keymap,
+    /// returning whether it resolves to a bound action, is a strict prefix
+    /// of one (the caller should keep waiting for more keys) or is unbound
+    pub fn resolve(&self, buffer: &[KeyEvent]) -> ChordResult<&A> {
+        let mut best: Option<&A> = None;
+        let mut pending = false;
+        for (sequence, action) in &self.0 {
+            match sequence.match_buffer(buffer) {
+                SequenceStatus::Match => best = Some(action),
+                SequenceStatus::PartialMatch => pending = true,
+                SequenceStatus::NoMatch => {}
+            }
+        }
+        match (best, pending) {
+            // a longer binding is still pending, so don't let an exact match
+            // on a shorter prefix shadow it yet (e.g. with `{g: A, "g g": B}`,
+            // the buffer `[g]` must keep waiting instead of firing `A`)
+            (_, true) => ChordResult::PartialMatch,
+            (Some(action), false) => ChordResult::Match(action),
+            (None, false) => ChordResult::NoMatch,
+        }
+    }
+}
+
+/// A collection of [Keymap]s, one per mode, for applications with several
+/// input modes (e.g. Helix's normal/insert/select modes).
+///
+/// ```
+/// use crokey::{key_seq, Keymap, Keymaps};
+/// #[derive(PartialEq, Eq, Hash)]
+/// enum Mode {
+///     Normal,
+///     Insert,
+/// }
+/// let mut keymaps: Keymaps<Mode, String> = Keymaps::default();
+/// let mut normal = Keymap::default();
+/// normal.insert(key_seq!(g g), "goto_start".to_string());
+/// keymaps.insert(Mode::Normal, normal);
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Keymaps<M: Eq + Hash, A>(HashMap<M, Keymap<A>>);
+
+impl<M: Eq + Hash, A> Default for Keymaps<M, A> {
+    fn default() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<M: Eq + Hash, A> Keymaps<M, A> {
+    /// set (or replace) the keymap for a mode
+    pub fn insert(&mut self, mode: M, keymap: Keymap<A>) -> Option<Keymap<A>> {
+        self.0.insert(mode, keymap)
+    }
+
+    /// resolve an input buffer against the keymap of the given mode; returns
+    /// [ChordResult::NoMatch] if the mode has no keymap at all
+    pub fn resolve(&self, mode: &M, buffer: &[KeyEvent]) -> ChordResult<&A> {
+        match self.0.get(mode) {
+            Some(keymap) => keymap.resolve(buffer),
+            None => ChordResult::NoMatch,
+        }
+    }
+}
+
+/// The result of resolving an input buffer of key events against a keymap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChordResult<A> {
+    /// the buffer resolves to this bound action
+    Match(A),
+    /// the buffer is a strict prefix of some binding: keep waiting for more keys
+    PartialMatch,
+    /// the buffer doesn't match, and can't become, any binding
+    NoMatch,
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::{key, key_seq}};
+
+    #[derive(PartialEq, Eq, Hash)]
+    enum Mode {
+        Normal,
+        Insert,
+    }
+
+    #[test]
+    fn resolve_keymap() {
+        let mut keymap: Keymap<&'static str> = Keymap::default();
+        keymap.insert(key_seq!(g g), "goto_start");
+        keymap.insert(key_seq!(ctrl-s), "save");
+        assert_eq!(keymap.resolve(&[key!(g)]), ChordResult::PartialMatch);
+        assert_eq!(
+            keymap.resolve(&[key!(g), key!(g)]),
+            ChordResult::Match(&"goto_start"),
+        );
+        assert_eq!(keymap.resolve(&[key!(ctrl-s)]), ChordResult::Match(&"save"));
+        assert_eq!(keymap.resolve(&[key!(x)]), ChordResult::NoMatch);
+    }
+
+    #[test]
+    fn resolve_overlapping_bindings() {
+        // `g` is both a binding of its own and a strict prefix of `g g`; the
+        // longer binding must stay reachable instead of being shadowed
+        let mut keymap: Keymap<&'static str> = Keymap::default();
+        keymap.insert(key_seq!(g), "g");
+        keymap.insert(key_seq!(g g), "goto_start");
+        assert_eq!(keymap.resolve(&[key!(g)]), ChordResult::PartialMatch);
+        assert_eq!(
+            keymap.resolve(&[key!(g), key!(g)]),
+            ChordResult::Match(&"goto_start"),
+        );
+    }
+
+    #[test]
+    fn resolve_keymaps() {
+        let mut normal = Keymap::default();
+        normal.insert(key_seq!(g g), "goto_start");
+        let mut insert = Keymap::default();
+        insert.insert(key_seq!(esc), "normal_mode");
+        let mut keymaps: Keymaps<Mode, &'static str> = Keymaps::default();
+        keymaps.insert(Mode::Normal, normal);
+        keymaps.insert(Mode::Insert, insert);
+        assert_eq!(
+            keymaps.resolve(&Mode::Insert, &[key!(esc)]),
+            ChordResult::Match(&"normal_mode"),
+        );
+        assert_eq!(
+            keymaps.resolve(&Mode::Normal, &[key!(esc)]),
+            ChordResult::NoMatch,
+        );
+    }
+}
@@ -107,16 +107,76 @@
 //! The [CroKey] type wraps `KeyEvent` and may be convenient as it implements `FromStr`,
 //! `Deserialize`, and `Display`, but its use is optional. The "deser_keybindings" example
 //! uses TOML and demonstrates how to have `KeyEvent` keys in the map instead of `Crokey`.
+//!
+//! ## Multi-key chord sequences
+//!
+//! Some keybindings are made of several keys typed in a row, such as `g g`
+//! or `ctrl-x ctrl-s`. The [KeySequence] type models that, with the same
+//! `parse` / `key_seq!` / `Display` / Serde story as single keys:
+//!
+//! ```
+//! use crokey::{key_seq, KeySequence};
+//! assert_eq!(
+//!     KeySequence::parse("ctrl-x ctrl-s").unwrap(),
+//!     key_seq!(ctrl-x ctrl-s),
+//! );
+//! ```
+//!
+//! Applications typically feed the keys they receive, one at a time, into a
+//! buffer and check it against the bound sequences with
+//! [KeySequence::match_buffer], which tells whether the buffer is a
+//! [SequenceStatus::Match], a [SequenceStatus::PartialMatch] (keep waiting
+//! for more keys) or a [SequenceStatus::NoMatch].
+//!
+//! ## Mode-aware keymaps
+//!
+//! [Keymap] and [Keymaps] build on [KeySequence] to give you a complete,
+//! config-driven keybinding layer, with one [Keymap] per input mode:
+//!
+//! ```
+//! use crokey::{key_seq, Keymap, Keymaps, ChordResult};
+//! #[derive(PartialEq, Eq, std::hash::Hash)]
+//! enum Mode { Normal, Insert }
+//! let mut normal = Keymap::default();
+//! normal.insert(key_seq!(g g), "goto_start".to_string());
+//! let mut keymaps: Keymaps<Mode, String> = Keymaps::default();
+//! keymaps.insert(Mode::Normal, normal);
+//! assert_eq!(
+//!     keymaps.resolve(&Mode::Normal, &[crokey::key!(g)]),
+//!     ChordResult::PartialMatch,
+//! );
+//! ```
+//!
+//! ## A configurable, symmetric parser
+//!
+//! [KeyEventFormat] can be configured to emit strings `parse` can't read
+//! back, e.g. `with_implicit_shift()` or custom modifier tokens. [KeyEventParser]
+//! is configured the same way and inverts it, with the caveats documented on
+//! [KeyEventParser]:
+//!
+//! ```
+//! use crokey::{KeyEventFormat, KeyEventParser, key};
+//! let format = KeyEventFormat::default().with_implicit_shift().with_control("^");
+//! let parser = KeyEventParser::default().with_implicit_shift().with_control("^");
+//! let key_event = key!(ctrl-c);
+//! assert_eq!(parser.parse(&format.to_string(key_event)).unwrap(), key_event);
+//! ```
 
 mod format;
+mod keymap;
 mod parse;
+mod parser;
+mod sequence;
 mod wrapper;
 
 pub use {
     crossterm,
     crokey_proc_macros::*,
     format::*,
+    keymap::*,
     parse::*,
+    parser::*,
+    sequence::*,
     wrapper::*,
 };
 
@@ -142,6 +202,29 @@ pub const fn as_letter(key: KeyEvent) -> Option<char> {
     }
 }
 
+/// normalize a key event so that it compares equal regardless of how the
+/// terminal reported the SHIFT modifier on a character key.
+///
+/// Some terminals report an uppercase letter as `Char('A')` with no SHIFT
+/// modifier, others as `Char('a')` with SHIFT set, and some printable
+/// symbols are reported as `shift-'?'` while others just send `'?'` with no
+/// modifier at all. This folds any `KeyCode::Char` which has the SHIFT
+/// modifier into its shifted form (uppercased) and clears the SHIFT bit, so
+/// all of those variants become the same event. A char without SHIFT is
+/// left as it is (a lowercase `a` stays `a`).
+pub fn normalize(key: KeyEvent) -> KeyEvent {
+    match key.code {
+        KeyCode::Char(c) if key.modifiers.contains(KeyModifiers::SHIFT) || c.is_ascii_uppercase() => {
+            KeyEvent {
+                code: KeyCode::Char(c.to_ascii_uppercase()),
+                modifiers: key.modifiers.difference(KeyModifiers::SHIFT),
+                ..key
+            }
+        }
+        _ => key,
+    }
+}
+
 /// check and expand at compile-time the provided expression
 /// into a valid KeyEvent.
 ///
@@ -175,10 +258,27 @@ macro_rules! key {
     };
 }
 
-// Not public API. This is internal and to be used only by `key!`.
+/// check and expand at compile-time the provided space separated list of
+/// key definitions into a valid [KeySequence].
+///
+/// For example:
+/// ```
+/// # use crokey::key_seq;
+/// let key_sequence = key_seq!(ctrl-x ctrl-s);
+/// ```
+/// lets you define a multi-key chord the same way `key!` lets you define
+/// a single key, with the same quoting rules for non-identifier keys.
+#[macro_export]
+macro_rules! key_seq {
+    ($($tt:tt)*) => {
+        $crate::__private::key_seq!(($crate) $($tt)*)
+    };
+}
+
+// Not public API. This is internal and to be used only by `key!` and `key_seq!`.
 #[doc(hidden)]
 pub mod __private {
-    pub use crokey_proc_macros::key;
+    pub use crokey_proc_macros::{key, key_seq};
     pub use crossterm;
 
     use crossterm::event::KeyModifiers;
@@ -186,12 +286,49 @@ pub mod __private {
     pub const MODS_CTRL: KeyModifiers = KeyModifiers::CONTROL;
     pub const MODS_ALT: KeyModifiers = KeyModifiers::ALT;
     pub const MODS_SHIFT: KeyModifiers = KeyModifiers::SHIFT;
+    pub const MODS_SUPER: KeyModifiers = KeyModifiers::SUPER;
+    pub const MODS_HYPER: KeyModifiers = KeyModifiers::HYPER;
+    pub const MODS_META: KeyModifiers = KeyModifiers::META;
     pub const MODS_CTRL_ALT: KeyModifiers = KeyModifiers::CONTROL.union(KeyModifiers::ALT);
     pub const MODS_ALT_SHIFT: KeyModifiers = KeyModifiers::ALT.union(KeyModifiers::SHIFT);
     pub const MODS_CTRL_SHIFT: KeyModifiers = KeyModifiers::CONTROL.union(KeyModifiers::SHIFT);
     pub const MODS_CTRL_ALT_SHIFT: KeyModifiers = KeyModifiers::CONTROL
         .union(KeyModifiers::ALT)
         .union(KeyModifiers::SHIFT);
+
+    /// combine an arbitrary set of modifiers, used to support `super-`/
+    /// `hyper-`/`meta-` (and any combination with `ctrl-`/`alt-`/`shift-`)
+    /// without hardcoding a named constant for every one of the 64 possible
+    /// combinations.
+    pub const fn mods(
+        ctrl: bool,
+        alt: bool,
+        shift: bool,
+        super_: bool,
+        hyper: bool,
+        meta: bool,
+    ) -> KeyModifiers {
+        let mut mods = KeyModifiers::NONE;
+        if ctrl {
+            mods = mods.union(KeyModifiers::CONTROL);
+        }
+        if alt {
+            mods = mods.union(KeyModifiers::ALT);
+        }
+        if shift {
+            mods = mods.union(KeyModifiers::SHIFT);
+        }
+        if super_ {
+            mods = mods.union(KeyModifiers::SUPER);
+        }
+        if hyper {
+            mods = mods.union(KeyModifiers::HYPER);
+        }
+        if meta {
+            mods = mods.union(KeyModifiers::META);
+        }
+        mods
+    }
 }
 
 #[cfg(test)]
@@ -210,6 +347,9 @@ mod tests {
         key!(alt - shift - f10);
         key!(ctrl - shift - f10);
         key!(ctrl - alt - shift - enter);
+        key!(super - a);
+        key!(hyper - meta - x);
+        key!(ctrl - c: release);
     };
 
     fn no_mod(code: KeyCode) -> KeyEvent {
@@ -4,7 +4,7 @@
 //! - describing key combinations in strings
 
 use {
-    crossterm::event::{KeyCode::*, KeyEvent, KeyModifiers},
+    crossterm::event::{KeyCode::*, KeyEvent, KeyEventKind, KeyModifiers},
     std::fmt,
 };
 
@@ -34,6 +34,11 @@ impl std::error::Error for ParseKeyError {}
 /// The char we receive as code from crossterm is usually lowercase
 /// but uppercase when it was typed with shift (i.e. we receive
 /// "g" for a lowercase, and "shift-G" for an uppercase)
+///
+/// The Kitty keyboard protocol's `super-`, `hyper-`, and `meta-` modifiers
+/// are also accepted, in any combination with `ctrl-`/`alt-`/`shift-`, as
+/// well as an optional trailing kind suffix such as `ctrl-c:release` (the
+/// kind defaults to `press` when not given).
 pub fn parse(raw: &str) -> Result<KeyEvent, ParseKeyError> {
     let mut modifiers = KeyModifiers::empty();
     let raw = raw.to_ascii_lowercase();
@@ -48,10 +53,29 @@ pub fn parse(raw: &str) -> Result<KeyEvent, ParseKeyError> {
         } else if let Some(end) = raw.strip_prefix("shift-") {
             raw = end;
             modifiers.insert(KeyModifiers::SHIFT);
+        } else if let Some(end) = raw.strip_prefix("super-") {
+            raw = end;
+            modifiers.insert(KeyModifiers::SUPER);
+        } else if let Some(end) = raw.strip_prefix("hyper-") {
+            raw = end;
+            modifiers.insert(KeyModifiers::HYPER);
+        } else if let Some(end) = raw.strip_prefix("meta-") {
+            raw = end;
+            modifiers.insert(KeyModifiers::META);
         } else {
             break;
         }
     }
+    let mut kind = KeyEventKind::Press;
+    if let Some(rest) = raw.strip_suffix(":press") {
+        raw = rest;
+    } else if let Some(rest) = raw.strip_suffix(":repeat") {
+        raw = rest;
+        kind = KeyEventKind::Repeat;
+    } else if let Some(rest) = raw.strip_suffix(":release") {
+        raw = rest;
+        kind = KeyEventKind::Release;
+    }
     let code = match raw {
         "esc" => Esc,
         "enter" => Enter,
@@ -92,7 +116,11 @@ pub fn parse(raw: &str) -> Result<KeyEvent, ParseKeyError> {
         c if c.len() == 1 => {
             let mut c = c.chars().next().unwrap();
             if modifiers.contains(KeyModifiers::SHIFT) {
+                // the shift is baked into the char itself (as a crossterm
+                // terminal would report it), so the modifier is dropped;
+                // see `normalize`, which folds the two back together.
                 c = c.to_ascii_uppercase();
+                modifiers.remove(KeyModifiers::SHIFT);
             }
             Char(c)
         }
@@ -100,7 +128,15 @@ pub fn parse(raw: &str) -> Result<KeyEvent, ParseKeyError> {
             return Err(ParseKeyError::new(raw));
         }
     };
-    Ok(KeyEvent::new(code, modifiers))
+    // named char keys (`space`, `hyphen`, `minus`) get the same folding
+    let code = match code {
+        Char(c) if modifiers.contains(KeyModifiers::SHIFT) => {
+            modifiers.remove(KeyModifiers::SHIFT);
+            Char(c.to_ascii_uppercase())
+        }
+        code => code,
+    };
+    Ok(KeyEvent::new_with_kind(code, modifiers, kind))
 }
 
 #[test]
@@ -125,12 +161,12 @@ fn check_key_parsing() {
     check_ok("alt-enter", KeyEvent::new(Enter, KeyModifiers::ALT));
     check_ok("insert", KeyEvent::from(Insert));
     check_ok("ctrl-q", KeyEvent::new(Char('q'), KeyModifiers::CONTROL));
-    check_ok("shift-q", KeyEvent::new(Char('Q'), KeyModifiers::SHIFT));
+    check_ok("shift-q", KeyEvent::new(Char('Q'), KeyModifiers::NONE));
     check_ok("ctrl-Q", KeyEvent::new(Char('q'), KeyModifiers::CONTROL));
-    check_ok("shift-Q", KeyEvent::new(Char('Q'), KeyModifiers::SHIFT));
+    check_ok("shift-Q", KeyEvent::new(Char('Q'), KeyModifiers::NONE));
     check_ok(
         "ctrl-shift-Q",
-        KeyEvent::new(Char('Q'), KeyModifiers::SHIFT | KeyModifiers::CONTROL),
+        KeyEvent::new(Char('Q'), KeyModifiers::CONTROL),
     );
     check_ok("-", KeyEvent::new(Char('-'), KeyModifiers::NONE));
     check_ok("Hyphen", KeyEvent::new(Char('-'), KeyModifiers::NONE));
@@ -139,16 +175,46 @@ fn check_key_parsing() {
     check_ok("alt-hyphen", KeyEvent::new(Char('-'), KeyModifiers::ALT));
     check_ok(
         "ctrl-Shift-alt-space",
-        KeyEvent::new(
-            Char(' '),
-            KeyModifiers::ALT | KeyModifiers::SHIFT | KeyModifiers::ALT | KeyModifiers::CONTROL,
-        ),
+        KeyEvent::new(Char(' '), KeyModifiers::ALT | KeyModifiers::CONTROL),
     );
     check_ok(
         "ctrl-shift-alt--",
-        KeyEvent::new(
-            Char('-'),
-            KeyModifiers::ALT | KeyModifiers::SHIFT | KeyModifiers::ALT | KeyModifiers::CONTROL,
-        ),
+        KeyEvent::new(Char('-'), KeyModifiers::ALT | KeyModifiers::CONTROL),
+    );
+    check_ok("super-a", KeyEvent::new(Char('a'), KeyModifiers::SUPER));
+    check_ok(
+        "hyper-meta-x",
+        KeyEvent::new(Char('x'), KeyModifiers::HYPER | KeyModifiers::META),
+    );
+    check_ok(
+        "ctrl-c:release",
+        KeyEvent::new_with_kind(Char('c'), KeyModifiers::CONTROL, KeyEventKind::Release),
+    );
+    check_ok(
+        "ctrl-c:repeat",
+        KeyEvent::new_with_kind(Char('c'), KeyModifiers::CONTROL, KeyEventKind::Repeat),
+    );
+    check_ok(
+        "ctrl-c:press",
+        KeyEvent::new_with_kind(Char('c'), KeyModifiers::CONTROL, KeyEventKind::Press),
+    );
+}
+
+#[test]
+fn check_normalize() {
+    use crate::{key, normalize};
+    // a shifted letter, whether the terminal reports it with an explicit
+    // SHIFT modifier or already folded into the uppercase char, normalizes
+    // to the same key event
+    assert_eq!(
+        normalize(KeyEvent::new(Char('a'), KeyModifiers::SHIFT)),
+        normalize(KeyEvent::new(Char('A'), KeyModifiers::NONE)),
+    );
+    // a shifted symbol normalizes to the same symbol with no SHIFT modifier
+    assert_eq!(
+        normalize(KeyEvent::new(Char('?'), KeyModifiers::SHIFT)),
+        KeyEvent::new(Char('?'), KeyModifiers::NONE),
     );
+    // unrelated keys stay distinct
+    assert_ne!(normalize(key!(ctrl-a)), normalize(key!(ctrl-shift-a)));
 }
@@ -0,0 +1,326 @@
+use {
+    crate::ParseKeyError,
+    crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+};
+
+/// A configurable parser for [KeyEvent], the inverse of
+/// [crate::KeyEventFormat]: when a parser and a formatter share the same
+/// configuration, `parser.parse(&format.to_string(key_event))` reproduces
+/// `key_event`, including its [crossterm::event::KeyEventKind].
+///
+/// Exceptions:
+/// - [with_implicit_shift](Self::with_implicit_shift) lets two
+///   distinct key events format to the same string (e.g. both `Char('A')` with
+///   no modifier and `Char('a')` with SHIFT render as `"A"`), so only one of
+///   them comes back out of `parse`; the other does not round-trip.
+/// - key codes which [crate::KeyEventFormat] renders as `"?"` (e.g.
+///   `KeyCode::CapsLock` or media keys) are read back as `Char('?')`.
+/// - a char equal to a modifier token (e.g. `'^'` when the control token is
+///   `"^"`) is read as that modifier, not as the char, and fails to parse.
+/// - the [crossterm::event::KeyEventState] isn't rendered, so it comes back empty.
+///
+/// ```
+/// use crokey::{KeyEventFormat, KeyEventParser};
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+///
+/// let format = KeyEventFormat::default()
+///     .with_implicit_shift()
+///     .with_control("^");
+/// let parser = KeyEventParser::default()
+///     .with_implicit_shift()
+///     .with_control("^");
+///
+/// let key_event = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+/// assert_eq!(parser.parse(&format.to_string(key_event)).unwrap(), key_event);
+/// ```
+///
+/// Custom named aliases can be added for tools using other conventions, such
+/// as Helix's `ret`/`lt`/`gt`:
+/// ```
+/// use crokey::KeyEventParser;
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+///
+/// let parser = KeyEventParser::default()
+///     .with_control("C-")
+///     .with_alt("A-")
+///     .with_shift("S-")
+///     .with_alias("ret", KeyCode::Enter)
+///     .with_alias("lt", KeyCode::Char('<'))
+///     .with_alias("gt", KeyCode::Char('>'));
+/// assert_eq!(parser.parse("C-s").unwrap(), KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL));
+/// assert_eq!(parser.parse("ret").unwrap(), KeyEvent::from(KeyCode::Enter));
+/// assert_eq!(parser.parse("lt").unwrap(), KeyEvent::from(KeyCode::Char('<')));
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyEventParser {
+    /// token expected at the start of the string when the control modifier is set
+    control: String,
+    /// token expected at the start of the string when the alt modifier is set
+    alt: String,
+    /// token expected at the start of the string when the shift modifier is set
+    shift: String,
+    /// token expected at the start of the string when the super modifier is set
+    super_: String,
+    /// token expected at the start of the string when the hyper modifier is set
+    hyper: String,
+    /// token expected at the start of the string when the meta modifier is set
+    meta: String,
+    /// when true, an uppercase letter with no shift token is read as the
+    /// lowercase letter with the SHIFT modifier set
+    implicit_shift: bool,
+    /// named key aliases, checked in order, e.g. `("Enter", KeyCode::Enter)`
+    aliases: Vec<(String, KeyCode)>,
+}
+
+fn default_aliases() -> Vec<(String, KeyCode)> {
+    use KeyCode::*;
+    vec![
+        ("Backspace".to_string(), Backspace),
+        ("Enter".to_string(), Enter),
+        ("Left".to_string(), Left),
+        ("Right".to_string(), Right),
+        ("Up".to_string(), Up),
+        ("Down".to_string(), Down),
+        ("Home".to_string(), Home),
+        ("End".to_string(), End),
+        ("PageUp".to_string(), PageUp),
+        ("PageDown".to_string(), PageDown),
+        ("Tab".to_string(), Tab),
+        ("BackTab".to_string(), BackTab),
+        ("Delete".to_string(), Delete),
+        ("Insert".to_string(), Insert),
+        ("Space".to_string(), Char(' ')),
+        ("Hyphen".to_string(), Char('-')),
+        ("Null".to_string(), Null),
+        ("Esc".to_string(), Esc),
+    ]
+}
+
+impl Default for KeyEventParser {
+    fn default() -> Self {
+        Self {
+            control: "Ctrl-".to_string(),
+            alt: "Alt-".to_string(),
+            shift: "Shift-".to_string(),
+            super_: "Super-".to_string(),
+            hyper: "Hyper-".to_string(),
+            meta: "Meta-".to_string(),
+            implicit_shift: false,
+            aliases: default_aliases(),
+        }
+    }
+}
+
+impl KeyEventParser {
+    /// set the token expected when the control modifier is set (default: "Ctrl-")
+    pub fn with_control<S: Into<String>>(mut self, control: S) -> Self {
+        self.control = control.into();
+        self
+    }
+    /// set the token expected when the alt modifier is set (default: "Alt-")
+    pub fn with_alt<S: Into<String>>(mut self, alt: S) -> Self {
+        self.alt = alt.into();
+        self
+    }
+    /// set the token expected when the shift modifier is set (default: "Shift-")
+    pub fn with_shift<S: Into<String>>(mut self, shift: S) -> Self {
+        self.shift = shift.into();
+        self
+    }
+    /// set the token expected when the super modifier is set (default: "Super-")
+    pub fn with_super<S: Into<String>>(mut self, super_: S) -> Self {
+        self.super_ = super_.into();
+        self
+    }
+    /// set the token expected when the hyper modifier is set (default: "Hyper-")
+    pub fn with_hyper<S: Into<String>>(mut self, hyper: S) -> Self {
+        self.hyper = hyper.into();
+        self
+    }
+    /// set the token expected when the meta modifier is set (default: "Meta-")
+    pub fn with_meta<S: Into<String>>(mut self, meta: S) -> Self {
+        self.meta = meta.into();
+        self
+    }
+    /// read an uppercase letter with no shift token as the lowercase letter
+    /// with the SHIFT modifier set, the inverse of [KeyEventFormat::with_implicit_shift]
+    ///
+    /// [KeyEventFormat::with_implicit_shift]: crate::KeyEventFormat::with_implicit_shift
+    pub fn with_implicit_shift(mut self) -> Self {
+        self.implicit_shift = true;
+        self
+    }
+    /// add (or override) a named alias for a key code, e.g. the Helix-style
+    /// `with_alias("ret", KeyCode::Enter)`
+    pub fn with_alias<S: Into<String>>(mut self, name: S, code: KeyCode) -> Self {
+        let name = name.into();
+        if let Some(entry) = self.aliases.iter_mut().find(|(n, _)| *n == name) {
+            entry.1 = code;
+        } else {
+            self.aliases.push((name, code));
+        }
+        self
+    }
+
+    /// parse a string, normally produced by a [KeyEventFormat] sharing this
+    /// parser's configuration, as a key event
+    ///
+    /// [KeyEventFormat]: crate::KeyEventFormat
+    pub fn parse(&self, raw: &str) -> Result<KeyEvent, ParseKeyError> {
+        let mut modifiers = KeyModifiers::empty();
+        let mut raw = raw;
+        loop {
+            if !self.control.is_empty() && raw.starts_with(self.control.as_str()) {
+                raw = &raw[self.control.len()..];
+                modifiers.insert(KeyModifiers::CONTROL);
+            } else if !self.alt.is_empty() && raw.starts_with(self.alt.as_str()) {
+                raw = &raw[self.alt.len()..];
+                modifiers.insert(KeyModifiers::ALT);
+            } else if !self.super_.is_empty() && raw.starts_with(self.super_.as_str()) {
+                raw = &raw[self.super_.len()..];
+                modifiers.insert(KeyModifiers::SUPER);
+            } else if !self.hyper.is_empty() && raw.starts_with(self.hyper.as_str()) {
+                raw = &raw[self.hyper.len()..];
+                modifiers.insert(KeyModifiers::HYPER);
+            } else if !self.meta.is_empty() && raw.starts_with(self.meta.as_str()) {
+                raw = &raw[self.meta.len()..];
+                modifiers.insert(KeyModifiers::META);
+            } else if !self.shift.is_empty() && raw.starts_with(self.shift.as_str()) {
+                raw = &raw[self.shift.len()..];
+                modifiers.insert(KeyModifiers::SHIFT);
+            } else {
+                break;
+            }
+        }
+        let mut kind = KeyEventKind::Press;
+        if let Some(rest) = raw.strip_suffix(":press") {
+            raw = rest;
+        } else if let Some(rest) = raw.strip_suffix(":repeat") {
+            raw = rest;
+            kind = KeyEventKind::Repeat;
+        } else if let Some(rest) = raw.strip_suffix(":release") {
+            raw = rest;
+            kind = KeyEventKind::Release;
+        }
+        if let Some((_, code)) = self.aliases.iter().find(|(name, _)| name == raw) {
+            let code = *code;
+            if code == KeyCode::BackTab {
+                // Crossterm always sends SHIFT with backtab
+                modifiers.insert(KeyModifiers::SHIFT);
+            }
+            return Ok(KeyEvent::new_with_kind(code, modifiers, kind));
+        }
+        if let Some(n) = raw.strip_prefix('F').and_then(|n| n.parse::<u8>().ok()) {
+            return Ok(KeyEvent::new_with_kind(KeyCode::F(n), modifiers, kind));
+        }
+        let mut chars = raw.chars();
+        let (Some(c), None) = (chars.next(), chars.next()) else {
+            return Err(ParseKeyError::new(raw));
+        };
+        let code = if self.implicit_shift && c.is_ascii_uppercase() {
+            modifiers.insert(KeyModifiers::SHIFT);
+            KeyCode::Char(c.to_ascii_lowercase())
+        } else {
+            KeyCode::Char(c)
+        };
+        Ok(KeyEvent::new_with_kind(code, modifiers, kind))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::KeyEventFormat,
+        crossterm::event::{KeyCode::*, KeyEvent, KeyEventKind},
+    };
+
+    fn check_round_trip(format: &KeyEventFormat, parser: &KeyEventParser, key_event: KeyEvent) {
+        let s = format.to_string(key_event);
+        assert_eq!(
+            parser.parse(&s).unwrap(),
+            key_event,
+            "round trip failed for {key_event:?} (formatted as {s:?})",
+        );
+    }
+
+    #[test]
+    fn round_trip_default() {
+        let format = KeyEventFormat::default();
+        let parser = KeyEventParser::default();
+        check_round_trip(&format, &parser, KeyEvent::new(Char('c'), KeyModifiers::CONTROL));
+        check_round_trip(&format, &parser, KeyEvent::new(Char('a'), KeyModifiers::SHIFT));
+        check_round_trip(&format, &parser, KeyEvent::new(Enter, KeyModifiers::ALT));
+        check_round_trip(&format, &parser, KeyEvent::new(Char('-'), KeyModifiers::ALT));
+    }
+
+    #[test]
+    fn round_trip_kind() {
+        let format = KeyEventFormat::default();
+        let parser = KeyEventParser::default();
+        check_round_trip(
+            &format,
+            &parser,
+            KeyEvent::new_with_kind(Char('c'), KeyModifiers::CONTROL, KeyEventKind::Press),
+        );
+        check_round_trip(
+            &format,
+            &parser,
+            KeyEvent::new_with_kind(Char('c'), KeyModifiers::CONTROL, KeyEventKind::Repeat),
+        );
+        check_round_trip(
+            &format,
+            &parser,
+            KeyEvent::new_with_kind(Char('c'), KeyModifiers::CONTROL, KeyEventKind::Release),
+        );
+    }
+
+    #[test]
+    fn round_trip_compact_implicit_shift() {
+        let format = KeyEventFormat::default().with_implicit_shift().with_control("^");
+        let parser = KeyEventParser::default().with_implicit_shift().with_control("^");
+        check_round_trip(&format, &parser, KeyEvent::new(Char('c'), KeyModifiers::CONTROL));
+        check_round_trip(&format, &parser, KeyEvent::new(Char('a'), KeyModifiers::SHIFT));
+        check_round_trip(&format, &parser, KeyEvent::new(Char('c'), KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn implicit_shift_collapses_normalized_uppercase() {
+        // `with_implicit_shift` renders both `Char('A')` with no modifier and
+        // `Char('a')` with SHIFT as "A"; `parse` can only recover one of them
+        // (the lowercase-plus-SHIFT form), so the normalized canonical form
+        // used elsewhere in the crate (see `normalize`) does not round-trip
+        let format = KeyEventFormat::default().with_implicit_shift();
+        let parser = KeyEventParser::default().with_implicit_shift();
+        let normalized = KeyEvent::new(Char('A'), KeyModifiers::NONE);
+        let s = format.to_string(normalized);
+        assert_eq!(s, "A");
+        assert_ne!(parser.parse(&s).unwrap(), normalized);
+        assert_eq!(
+            parser.parse(&s).unwrap(),
+            KeyEvent::new(Char('a'), KeyModifiers::SHIFT),
+        );
+    }
+
+    #[test]
+    fn helix_style_prefixes() {
+        let parser = KeyEventParser::default()
+            .with_control("C-")
+            .with_alt("A-")
+            .with_shift("S-")
+            .with_alias("ret", Enter)
+            .with_alias("lt", Char('<'))
+            .with_alias("gt", Char('>'));
+        assert_eq!(
+            parser.parse("C-s").unwrap(),
+            KeyEvent::new(Char('s'), KeyModifiers::CONTROL),
+        );
+        assert_eq!(parser.parse("ret").unwrap(), KeyEvent::from(Enter));
+        assert_eq!(parser.parse("lt").unwrap(), KeyEvent::from(Char('<')));
+        assert_eq!(parser.parse("gt").unwrap(), KeyEvent::from(Char('>')));
+        assert_eq!(
+            parser.parse("C-A-lt").unwrap(),
+            KeyEvent::new(Char('<'), KeyModifiers::CONTROL | KeyModifiers::ALT),
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! Support for multi-key chord sequences, e.g. `g g` or `ctrl-x ctrl-s`.
+
+use {
+    crate::{parse, KeyEventFormat, ParseKeyError},
+    crossterm::event::KeyEvent,
+    std::{fmt, str::FromStr},
+};
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// An ordered sequence of one or several key events, used to describe
+/// multi-key chords such as `g g` or `ctrl-x ctrl-s`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KeySequence(pub Vec<KeyEvent>);
+
+impl KeySequence {
+    /// parse a whitespace separated sequence of key definitions, e.g.
+    /// "ctrl-x ctrl-s", reusing the single key `parse` function on each token
+    pub fn parse(raw: &str) -> Result<Self, ParseKeyError> {
+        let keys = raw
+            .split_whitespace()
+            .map(parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        if keys.is_empty() {
+            return Err(ParseKeyError::new(raw));
+        }
+        Ok(Self(keys))
+    }
+
+    /// compare an input buffer of already received key events to this
+    /// sequence, telling the caller whether it should keep waiting for
+    /// more keys
+    pub fn match_buffer(&self, buffer: &[KeyEvent]) -> SequenceStatus {
+        if buffer.len() > self.0.len() || buffer != &self.0[..buffer.len()] {
+            return SequenceStatus::NoMatch;
+        }
+        if buffer.len() == self.0.len() {
+            SequenceStatus::Match
+        } else {
+            SequenceStatus::PartialMatch
+        }
+    }
+
+    /// render this sequence as a string, formatting each key with the given
+    /// [KeyEventFormat] and joining them with the given separator
+    ///
+    /// ```
+    /// use crokey::{key_seq, KeyEventFormat};
+    /// let seq = key_seq!(ctrl-x ctrl-s);
+    /// assert_eq!(
+    ///     seq.to_string_with(&KeyEventFormat::default(), " then "),
+    ///     "Ctrl-x then Ctrl-s",
+    /// );
+    /// ```
+    pub fn to_string_with(&self, format: &KeyEventFormat, sep: &str) -> String {
+        self.0
+            .iter()
+            .map(|key| format.to_string(*key))
+            .collect::<Vec<_>>()
+            .join(sep)
+    }
+}
+
+/// The result of comparing an input buffer of key events to one or several
+/// key sequences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceStatus {
+    /// the buffer exactly matches a sequence
+    Match,
+    /// the buffer is a strict prefix of a longer sequence: the application
+    /// should keep waiting for more keys instead of treating this as unbound
+    PartialMatch,
+    /// the buffer can't lead to a match: it should be discarded (or treated
+    /// as unbound)
+    NoMatch,
+}
+
+impl From<KeyEvent> for KeySequence {
+    fn from(key_event: KeyEvent) -> Self {
+        Self(vec![key_event])
+    }
+}
+
+impl FromStr for KeySequence {
+    type Err = ParseKeyError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_string_with(&crate::STANDARD_FORMAT, " "))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for KeySequence {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for KeySequence {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::parse(&s).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::key,
+    };
+
+    #[test]
+    fn parse_sequence() {
+        assert_eq!(
+            KeySequence::parse("ctrl-x ctrl-s").unwrap(),
+            KeySequence(vec![key!(ctrl-x), key!(ctrl-s)]),
+        );
+        assert_eq!(
+            KeySequence::parse("g g").unwrap(),
+            KeySequence(vec![key!(g), key!(g)]),
+        );
+        assert!(KeySequence::parse("").is_err());
+    }
+
+    #[test]
+    fn display_sequence() {
+        let seq = KeySequence::parse("ctrl-x ctrl-s").unwrap();
+        assert_eq!(seq.to_string(), "Ctrl-x Ctrl-s");
+        let format = crate::KeyEventFormat::default().with_control("^");
+        assert_eq!(seq.to_string_with(&format, " then "), "^x then ^s");
+    }
+
+    #[test]
+    fn match_buffer() {
+        let seq = KeySequence::parse("g g").unwrap();
+        assert_eq!(seq.match_buffer(&[]), SequenceStatus::PartialMatch);
+        assert_eq!(seq.match_buffer(&[key!(g)]), SequenceStatus::PartialMatch);
+        assert_eq!(seq.match_buffer(&[key!(g), key!(g)]), SequenceStatus::Match);
+        assert_eq!(seq.match_buffer(&[key!(x)]), SequenceStatus::NoMatch);
+        assert_eq!(
+            seq.match_buffer(&[key!(g), key!(g), key!(g)]),
+            SequenceStatus::NoMatch,
+        );
+    }
+}
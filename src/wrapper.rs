@@ -0,0 +1,73 @@
+use {
+    crate::{parse, ParseKeyError},
+    crossterm::event::KeyEvent,
+    std::{fmt, ops::Deref, str::FromStr},
+};
+
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A simple wrapper around [KeyEvent], implementing `FromStr`, `Display`,
+/// and, with the "serde" feature, `Serialize`/`Deserialize`.
+///
+/// It's convenient when you want a `HashMap<CroKey, Action>` loaded directly
+/// from a configuration file, but its use is entirely optional: you can
+/// also use `parse` and `KeyEvent` directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CroKey(pub KeyEvent);
+
+impl Deref for CroKey {
+    type Target = KeyEvent;
+    fn deref(&self) -> &KeyEvent {
+        &self.0
+    }
+}
+
+impl CroKey {
+    /// return this key event with its SHIFT modifier folded into the char,
+    /// so that keys reported differently by different terminals (e.g.
+    /// `shift-a` vs `A`) compare equal; see [crate::normalize].
+    pub fn normalized(self) -> Self {
+        Self(crate::normalize(self.0))
+    }
+}
+
+impl From<KeyEvent> for CroKey {
+    fn from(key_event: KeyEvent) -> Self {
+        Self(key_event)
+    }
+}
+
+impl From<CroKey> for KeyEvent {
+    fn from(crokey: CroKey) -> Self {
+        crokey.0
+    }
+}
+
+impl FromStr for CroKey {
+    type Err = ParseKeyError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s).map(Self)
+    }
+}
+
+impl fmt::Display for CroKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", crate::STANDARD_FORMAT.to_string(self.0))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for CroKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for CroKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(de::Error::custom)
+    }
+}